@@ -11,4 +11,10 @@ pub enum ContractError {
 
     #[error("Royalty percentage must be between 0 and 100")]
     InvalidRoyaltyPercentage,
+
+    #[error("Invalid royalty payment address: {address}")]
+    InvalidRoyaltyPaymentAddress { address: String },
+
+    #[error("Royalty payment address is required when royalty percentage is set")]
+    MissingRoyaltyPaymentAddress,
 }
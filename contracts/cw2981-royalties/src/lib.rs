@@ -5,7 +5,7 @@ pub mod query;
 pub use query::{check_royalties, query_royalties_info};
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_binary, Empty};
+use cosmwasm_std::{to_json_binary, Api, Empty};
 use cw721_base::Cw721Contract;
 pub use cw721_base::{InstantiateMsg, MinterResponse};
 
@@ -40,11 +40,36 @@ pub struct Metadata {
     /// royalties are owed on this token if it is Some
     pub royalty_percentage: Option<u64>,
     /// The payment address, may be different to or the same
-    /// as the minter addr
-    /// question: how do we validate this?
+    /// as the minter addr. Must be a valid address, and is
+    /// required if royalty_percentage is above 0
     pub royalty_payment_address: Option<String>,
 }
 
+impl Metadata {
+    /// Checks that royalty_percentage is at most 100 and that
+    /// royalty_payment_address is a valid address, required if
+    /// a royalty is owed
+    pub fn validate_royalties(&self, api: &dyn Api) -> Result<(), ContractError> {
+        // no need to check < 0 because royalty_percentage is u64
+        if let Some(royalty_percentage) = self.royalty_percentage {
+            if royalty_percentage > 100 {
+                return Err(ContractError::InvalidRoyaltyPercentage);
+            }
+            if royalty_percentage > 0 && self.royalty_payment_address.is_none() {
+                return Err(ContractError::MissingRoyaltyPaymentAddress);
+            }
+        }
+        if let Some(address) = &self.royalty_payment_address {
+            api.addr_validate(address).map_err(|_| {
+                ContractError::InvalidRoyaltyPaymentAddress {
+                    address: address.clone(),
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
+
 pub type Extension = Option<Metadata>;
 
 pub type MintExtension = Option<Extension>;
@@ -80,19 +105,11 @@ pub mod entry {
         msg: ExecuteMsg,
     ) -> Result<Response, ContractError> {
         if let ExecuteMsg::Mint {
-            extension:
-                Some(Metadata {
-                    royalty_percentage: Some(royalty_percentage),
-                    ..
-                }),
+            extension: Some(metadata),
             ..
         } = &msg
         {
-            // validate royalty_percentage to be between 0 and 100
-            // no need to check < 0 because royalty_percentage is u64
-            if *royalty_percentage > 100 {
-                return Err(ContractError::InvalidRoyaltyPercentage);
-            }
+            metadata.validate_royalties(deps.api)?;
         }
 
         Cw2981Contract::default()
@@ -188,8 +205,42 @@ mod tests {
             }),
         };
         // mint will return StdError
-        let err = entry::execute(deps.as_mut(), mock_env(), info, exec_msg).unwrap_err();
+        let err = entry::execute(deps.as_mut(), mock_env(), info.clone(), exec_msg).unwrap_err();
         assert_eq!(err, ContractError::InvalidRoyaltyPercentage);
+
+        let exec_msg = ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: "john".to_string(),
+            token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
+            extension: Some(Metadata {
+                description: Some("Spaceship with Warp Drive".into()),
+                name: Some("Starship USS Enterprise".to_string()),
+                royalty_percentage: Some(10),
+                royalty_payment_address: Some("JOHN".to_string()),
+                ..Metadata::default()
+            }),
+        };
+        let err = entry::execute(deps.as_mut(), mock_env(), info.clone(), exec_msg).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidRoyaltyPaymentAddress {
+                address: "JOHN".to_string()
+            }
+        );
+
+        let exec_msg = ExecuteMsg::Mint {
+            token_id: token_id.to_string(),
+            owner: "john".to_string(),
+            token_uri: Some("https://starships.example.com/Starship/Enterprise.json".into()),
+            extension: Some(Metadata {
+                description: Some("Spaceship with Warp Drive".into()),
+                name: Some("Starship USS Enterprise".to_string()),
+                royalty_percentage: Some(10),
+                ..Metadata::default()
+            }),
+        };
+        let err = entry::execute(deps.as_mut(), mock_env(), info, exec_msg).unwrap_err();
+        assert_eq!(err, ContractError::MissingRoyaltyPaymentAddress);
     }
 
     #[test]
@@ -2,7 +2,8 @@
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Empty, Response, StdError, WasmMsg,
+    from_json, to_json_binary, Addr, Coin, CosmosMsg, DepsMut, Empty, Order, Response, StdError,
+    WasmMsg,
 };
 
 use cw721::{
@@ -11,6 +12,11 @@ use cw721::{
 };
 use cw_ownable::OwnershipError;
 
+use crate::msg::{
+    ApprovalsSummaryResponse, CanTransferReason, CanTransferResponse, MintInfoResponse,
+    OwnerHistoryLengthResponse, SwapResponse,
+};
+use crate::state::{TransferPolicy, MAX_OWNER_HISTORY_LENGTH};
 use crate::{
     ContractError, Cw721Contract, ExecuteMsg, Extension, InstantiateMsg, MinterResponse, QueryMsg,
 };
@@ -272,7 +278,9 @@ fn burning() {
         extension: None,
     };
 
-    let burn_msg = ExecuteMsg::Burn { token_id };
+    let burn_msg = ExecuteMsg::Burn {
+        token_id: token_id.clone(),
+    };
 
     // mint some NFT
     let allowed = mock_info(MINTER, &[]);
@@ -280,6 +288,16 @@ fn burning() {
         .execute(deps.as_mut(), mock_env(), allowed.clone(), mint_msg)
         .unwrap();
 
+    // mint info is recorded
+    let mint_info = contract.mint_info(deps.as_ref(), token_id.clone()).unwrap();
+    assert_eq!(
+        mint_info,
+        MintInfoResponse {
+            minted_at: mock_env().block.time,
+            minted_by: MINTER.to_string(),
+        }
+    );
+
     // random not allowed to burn
     let random = mock_info("random", &[]);
     let err = contract
@@ -292,6 +310,9 @@ fn burning() {
         .execute(deps.as_mut(), mock_env(), allowed, burn_msg)
         .unwrap();
 
+    // mint info is removed along with the token
+    assert!(!contract.mint_info.has(deps.as_ref().storage, &token_id));
+
     // ensure num tokens decreases
     let count = contract.num_tokens(deps.as_ref()).unwrap();
     assert_eq!(0, count.count);
@@ -360,6 +381,572 @@ fn transferring_nft() {
     );
 }
 
+#[test]
+fn transfer_policy_allowlist() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "melt".to_string();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("venus"),
+        token_uri: None,
+        extension: None,
+    };
+    let minter = mock_info(MINTER, &[]);
+    contract
+        .execute(deps.as_mut(), mock_env(), minter, mint_msg)
+        .unwrap();
+
+    // defaults to open
+    let policy = contract.transfer_policy(deps.as_ref().storage).unwrap();
+    assert_eq!(policy, TransferPolicy::Open);
+
+    // only the contract owner can change the policy
+    let set_policy_msg = ExecuteMsg::SetTransferPolicy {
+        allowlist: Some(vec![String::from("market")]),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            set_policy_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            set_policy_msg,
+        )
+        .unwrap();
+    let policy = contract.transfer_policy(deps.as_ref().storage).unwrap();
+    assert_eq!(
+        policy,
+        TransferPolicy::Allowlist {
+            contracts: vec![Addr::unchecked("market")]
+        }
+    );
+
+    // operators not on the allowlist cannot transfer
+    let owner = mock_info("venus", &[]);
+    for operator in ["market", "zero-royalty-market"] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                owner.clone(),
+                ExecuteMsg::ApproveAll {
+                    operator: String::from(operator),
+                    expires: None,
+                },
+            )
+            .unwrap();
+    }
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: String::from("random"),
+        token_id: token_id.clone(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("zero-royalty-market", &[]),
+            transfer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TransferNotAllowed {
+            sender: String::from("zero-royalty-market")
+        }
+    );
+
+    // allowlisted operators can
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("market", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+
+    // and the owner can always move their own token
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            ExecuteMsg::TransferNft {
+                recipient: String::from("venus"),
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap();
+
+    // sending is restricted the same way
+    let send_msg = ExecuteMsg::SendNft {
+        contract: String::from("another_contract"),
+        token_id: token_id.clone(),
+        msg: to_json_binary("yes").unwrap(),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("zero-royalty-market", &[]),
+            send_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::TransferNotAllowed {
+            sender: String::from("zero-royalty-market")
+        }
+    );
+
+    // clearing the allowlist removes the restriction
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::SetTransferPolicy { allowlist: None },
+        )
+        .unwrap();
+    let policy = contract.transfer_policy(deps.as_ref().storage).unwrap();
+    assert_eq!(policy, TransferPolicy::Open);
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("zero-royalty-market", &[]),
+            send_msg,
+        )
+        .unwrap();
+}
+
+#[test]
+fn checking_can_transfer() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "melt".to_string();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::Mint {
+                token_id: token_id.clone(),
+                owner: String::from("venus"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            ExecuteMsg::Approve {
+                spender: String::from("market"),
+                token_id: token_id.clone(),
+                expires: None,
+            },
+        )
+        .unwrap();
+
+    // owner and approved spenders can transfer
+    for spender in ["venus", "market"] {
+        let res = contract
+            .can_transfer(
+                deps.as_ref(),
+                mock_env(),
+                String::from(spender),
+                token_id.clone(),
+            )
+            .unwrap();
+        assert_eq!(
+            res,
+            CanTransferResponse {
+                can_transfer: true,
+                reason: None,
+            }
+        );
+    }
+
+    // random cannot
+    let res = contract
+        .can_transfer(
+            deps.as_ref(),
+            mock_env(),
+            String::from("random"),
+            token_id.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        CanTransferResponse {
+            can_transfer: false,
+            reason: Some(CanTransferReason::NotOwnerOrApproved {}),
+        }
+    );
+
+    // the transfer policy is taken into account
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::SetTransferPolicy {
+                allowlist: Some(vec![]),
+            },
+        )
+        .unwrap();
+    let res = contract
+        .can_transfer(
+            deps.as_ref(),
+            mock_env(),
+            String::from("market"),
+            token_id.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        CanTransferResponse {
+            can_transfer: false,
+            reason: Some(CanTransferReason::TransferPolicy {}),
+        }
+    );
+
+    // unknown tokens are an error
+    contract
+        .can_transfer(
+            deps.as_ref(),
+            mock_env(),
+            String::from("venus"),
+            String::from("unknown"),
+        )
+        .unwrap_err();
+}
+
+#[test]
+fn tracking_owner_history() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "melt".to_string();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("venus"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), mock_env(), mock_info(MINTER, &[]), mint_msg)
+        .unwrap();
+
+    // freshly minted token has no history
+    let history = contract
+        .owner_history(deps.as_ref(), token_id.clone(), None)
+        .unwrap();
+    assert!(history.owners.is_empty());
+
+    // only the contract owner can change the history length, and only up to the max
+    let err = contract
+        .set_owner_history_length(deps.as_mut().storage, &Addr::unchecked("venus"), 2)
+        .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+    let err = contract
+        .set_owner_history_length(
+            deps.as_mut().storage,
+            &Addr::unchecked(MINTER),
+            MAX_OWNER_HISTORY_LENGTH + 1,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::OwnerHistoryTooLong {
+            max: MAX_OWNER_HISTORY_LENGTH
+        }
+    );
+    contract
+        .set_owner_history_length(deps.as_mut().storage, &Addr::unchecked(MINTER), 2)
+        .unwrap();
+    let res: OwnerHistoryLengthResponse = from_json(
+        contract
+            .query(deps.as_ref(), mock_env(), QueryMsg::OwnerHistoryLength {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.length, 2);
+
+    // pass the token along a chain of owners
+    for (owner, recipient) in [
+        ("venus", "mars"),
+        ("mars", "jupiter"),
+        ("jupiter", "saturn"),
+    ] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(owner, &[]),
+                ExecuteMsg::TransferNft {
+                    recipient: String::from(recipient),
+                    token_id: token_id.clone(),
+                },
+            )
+            .unwrap();
+    }
+
+    // only the two most recent previous owners are kept, most recent first
+    let history = contract
+        .owner_history(deps.as_ref(), token_id.clone(), None)
+        .unwrap();
+    assert_eq!(history.owners, vec!["jupiter", "mars"]);
+    let history = contract
+        .owner_history(deps.as_ref(), token_id.clone(), Some(1))
+        .unwrap();
+    assert_eq!(history.owners, vec!["jupiter"]);
+
+    // growing the buffer keeps the recorded owners in order
+    contract
+        .set_owner_history_length(deps.as_mut().storage, &Addr::unchecked(MINTER), 3)
+        .unwrap();
+    for (owner, recipient) in [("saturn", "uranus"), ("uranus", "neptune")] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                mock_info(owner, &[]),
+                ExecuteMsg::TransferNft {
+                    recipient: String::from(recipient),
+                    token_id: token_id.clone(),
+                },
+            )
+            .unwrap();
+    }
+    let history = contract
+        .owner_history(deps.as_ref(), token_id.clone(), None)
+        .unwrap();
+    assert_eq!(history.owners, vec!["uranus", "saturn", "jupiter"]);
+
+    // shrinking it is applied to queries right away
+    contract
+        .set_owner_history_length(deps.as_mut().storage, &Addr::unchecked(MINTER), 1)
+        .unwrap();
+    let history = contract
+        .owner_history(deps.as_ref(), token_id.clone(), None)
+        .unwrap();
+    assert_eq!(history.owners, vec!["uranus"]);
+
+    // history is cleared on burn
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("neptune", &[]),
+            ExecuteMsg::Burn {
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(!contract
+        .owner_history_heads
+        .has(deps.as_ref().storage, &token_id));
+    let slots = contract
+        .owner_history
+        .prefix(&token_id)
+        .keys(deps.as_ref().storage, None, None, Order::Ascending)
+        .count();
+    assert_eq!(slots, 0);
+}
+
+#[test]
+fn swapping_nfts() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let minter = mock_info(MINTER, &[]);
+    for (token_id, owner) in [("melt", "venus"), ("freeze", "mars")] {
+        contract
+            .execute(
+                deps.as_mut(),
+                mock_env(),
+                minter.clone(),
+                ExecuteMsg::Mint {
+                    token_id: String::from(token_id),
+                    owner: String::from(owner),
+                    token_uri: None,
+                    extension: None,
+                },
+            )
+            .unwrap();
+    }
+
+    // cannot swap a token for itself
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            ExecuteMsg::OfferSwap {
+                offered_token_id: String::from("melt"),
+                wanted_token_id: String::from("melt"),
+                expires: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::SelfSwap {});
+
+    // random cannot offer someone else's token
+    let expires = Expiration::AtHeight(mock_env().block.height + 10);
+    let offer_msg = ExecuteMsg::OfferSwap {
+        offered_token_id: String::from("melt"),
+        wanted_token_id: String::from("freeze"),
+        expires: Some(expires),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            offer_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+
+    // owner can, and the offered token is escrowed by the contract
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            offer_msg,
+        )
+        .unwrap();
+    let owner = contract
+        .owner_of(deps.as_ref(), mock_env(), String::from("melt"), false)
+        .unwrap();
+    assert_eq!(owner.owner, mock_env().contract.address.to_string());
+    let swap = contract.swap(deps.as_ref(), String::from("melt")).unwrap();
+    assert_eq!(
+        swap,
+        SwapResponse {
+            offered_token_id: String::from("melt"),
+            offerer: String::from("venus"),
+            wanted_token_id: String::from("freeze"),
+            expires,
+        }
+    );
+
+    // random cannot accept without access to the wanted token
+    let accept_msg = ExecuteMsg::AcceptSwap {
+        offered_token_id: String::from("melt"),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("random", &[]),
+            accept_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+
+    // expired offers cannot be accepted
+    let mut late_env = mock_env();
+    late_env.block.height += 10;
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            late_env.clone(),
+            mock_info("mars", &[]),
+            accept_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::SwapExpired {
+            token_id: String::from("melt")
+        }
+    );
+    let swaps = contract
+        .swaps(deps.as_ref(), late_env, false, None, None)
+        .unwrap();
+    assert!(swaps.swaps.is_empty());
+
+    // owner of the wanted token can accept
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("mars", &[]),
+            accept_msg,
+        )
+        .unwrap();
+    let owner = contract
+        .owner_of(deps.as_ref(), mock_env(), String::from("melt"), false)
+        .unwrap();
+    assert_eq!(owner.owner, "mars");
+    let owner = contract
+        .owner_of(deps.as_ref(), mock_env(), String::from("freeze"), false)
+        .unwrap();
+    assert_eq!(owner.owner, "venus");
+    let swaps = contract
+        .swaps(deps.as_ref(), mock_env(), true, None, None)
+        .unwrap();
+    assert!(swaps.swaps.is_empty());
+
+    // offers can be cancelled by the offerer only
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            ExecuteMsg::OfferSwap {
+                offered_token_id: String::from("freeze"),
+                wanted_token_id: String::from("melt"),
+                expires: None,
+            },
+        )
+        .unwrap();
+    let cancel_msg = ExecuteMsg::CancelSwap {
+        offered_token_id: String::from("freeze"),
+    };
+    let err = contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("mars", &[]),
+            cancel_msg.clone(),
+        )
+        .unwrap_err();
+    assert_eq!(err, ContractError::Ownership(OwnershipError::NotOwner));
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("venus", &[]),
+            cancel_msg,
+        )
+        .unwrap();
+    let owner = contract
+        .owner_of(deps.as_ref(), mock_env(), String::from("freeze"), false)
+        .unwrap();
+    assert_eq!(owner.owner, "venus");
+    contract
+        .swap(deps.as_ref(), String::from("freeze"))
+        .unwrap_err();
+}
+
 #[test]
 fn sending_nft() {
     let mut deps = mock_dependencies();
@@ -570,6 +1157,122 @@ fn approving_revoking() {
     );
 }
 
+#[test]
+fn summarizing_approvals() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut());
+
+    let token_id = "grow".to_string();
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::Mint {
+                token_id: token_id.clone(),
+                owner: String::from("demeter"),
+                token_uri: None,
+                extension: None,
+            },
+        )
+        .unwrap();
+
+    // no approvals yet
+    let summary = contract
+        .approvals_summary(deps.as_ref(), mock_env(), token_id.clone())
+        .unwrap();
+    assert_eq!(
+        summary,
+        ApprovalsSummaryResponse {
+            active_approvals: 0,
+            earliest_height: None,
+            earliest_time: None,
+        }
+    );
+
+    let approve = |deps: DepsMut, spender: &str, expires: Expiration| {
+        contract
+            .execute(
+                deps,
+                mock_env(),
+                mock_info("demeter", &[]),
+                ExecuteMsg::Approve {
+                    spender: String::from(spender),
+                    token_id: token_id.clone(),
+                    expires: Some(expires),
+                },
+            )
+            .unwrap();
+    };
+
+    // approvals that never expire are counted but have no expiry
+    approve(deps.as_mut(), "buddy", Expiration::Never {});
+    let summary = contract
+        .approvals_summary(deps.as_ref(), mock_env(), token_id.clone())
+        .unwrap();
+    assert_eq!(summary.active_approvals, 1);
+    assert_eq!(summary.earliest_height, None);
+    assert_eq!(summary.earliest_time, None);
+
+    let env = mock_env();
+    approve(
+        deps.as_mut(),
+        "pal",
+        Expiration::AtTime(env.block.time.plus_seconds(10)),
+    );
+    approve(
+        deps.as_mut(),
+        "mate",
+        Expiration::AtHeight(env.block.height + 20),
+    );
+    approve(
+        deps.as_mut(),
+        "chum",
+        Expiration::AtHeight(env.block.height + 10),
+    );
+
+    // height and time based expirations are reported separately
+    let summary = contract
+        .approvals_summary(deps.as_ref(), mock_env(), token_id.clone())
+        .unwrap();
+    assert_eq!(summary.active_approvals, 4);
+    assert_eq!(summary.earliest_height, Some(env.block.height + 10));
+    assert_eq!(summary.earliest_time, Some(env.block.time.plus_seconds(10)));
+
+    // the time based approval expires first
+    let mut later = mock_env();
+    later.block.height += 2;
+    later.block.time = later.block.time.plus_seconds(15);
+    let summary = contract
+        .approvals_summary(deps.as_ref(), later, token_id.clone())
+        .unwrap();
+    assert_eq!(summary.active_approvals, 3);
+    assert_eq!(summary.earliest_height, Some(env.block.height + 10));
+    assert_eq!(summary.earliest_time, None);
+
+    // expired approvals are still listed, but not counted
+    let mut later = mock_env();
+    later.block.height += 15;
+    later.block.time = later.block.time.plus_seconds(75);
+    let approvals = contract
+        .approvals(deps.as_ref(), later.clone(), token_id.clone(), true)
+        .unwrap();
+    assert_eq!(approvals.approvals.len(), 4);
+    let summary: ApprovalsSummaryResponse = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                later,
+                QueryMsg::ApprovalsSummary { token_id },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(summary.active_approvals, 2);
+    assert_eq!(summary.earliest_height, Some(env.block.height + 20));
+    assert_eq!(summary.earliest_time, None);
+}
+
 #[test]
 fn approving_all_revoking_all() {
     let mut deps = mock_dependencies();
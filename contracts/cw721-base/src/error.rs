@@ -24,4 +24,16 @@ pub enum ContractError {
 
     #[error("No withdraw address set")]
     NoWithdrawAddress {},
+
+    #[error("Transfer policy does not allow transfers by: {sender}")]
+    TransferNotAllowed { sender: String },
+
+    #[error("Owner history length cannot exceed {max}")]
+    OwnerHistoryTooLong { max: u32 },
+
+    #[error("Cannot swap a token for itself")]
+    SelfSwap {},
+
+    #[error("Swap offer for {token_id} has expired")]
+    SwapExpired { token_id: String },
 }
@@ -3,14 +3,18 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use cosmwasm_std::{
-    Addr, BankMsg, Binary, Coin, CustomMsg, Deps, DepsMut, Env, MessageInfo, Response, Storage,
+    Addr, BankMsg, Binary, Coin, CustomMsg, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    Storage,
 };
 
 use cw721::{ContractInfoResponse, Cw721Execute, Cw721ReceiveMsg, Expiration};
 
 use crate::error::ContractError;
 use crate::msg::{ExecuteMsg, InstantiateMsg};
-use crate::state::{Approval, Cw721Contract, TokenInfo};
+use crate::state::{
+    Approval, Cw721Contract, MintInfo, SwapOffer, TokenInfo, TransferPolicy,
+    MAX_OWNER_HISTORY_LENGTH,
+};
 
 impl<'a, T, C, E, Q> Cw721Contract<'a, T, C, E, Q>
 where
@@ -58,7 +62,7 @@ where
                 owner,
                 token_uri,
                 extension,
-            } => self.mint(deps, info, token_id, owner, token_uri, extension),
+            } => self.mint_with_info(deps, env, info, token_id, owner, token_uri, extension),
             ExecuteMsg::Approve {
                 spender,
                 token_id,
@@ -90,6 +94,23 @@ where
                 self.remove_withdraw_address(deps.storage, &info.sender)
             }
             ExecuteMsg::WithdrawFunds { amount } => self.withdraw_funds(deps.storage, &amount),
+            ExecuteMsg::SetTransferPolicy { allowlist } => {
+                self.set_transfer_policy(deps, &info.sender, allowlist)
+            }
+            ExecuteMsg::SetOwnerHistoryLength { length } => {
+                self.set_owner_history_length(deps.storage, &info.sender, length)
+            }
+            ExecuteMsg::OfferSwap {
+                offered_token_id,
+                wanted_token_id,
+                expires,
+            } => self.offer_swap(deps, env, info, offered_token_id, wanted_token_id, expires),
+            ExecuteMsg::AcceptSwap { offered_token_id } => {
+                self.accept_swap(deps, env, info, offered_token_id)
+            }
+            ExecuteMsg::CancelSwap { offered_token_id } => {
+                self.cancel_swap(deps, info, offered_token_id)
+            }
         }
     }
 }
@@ -135,6 +156,34 @@ where
             .add_attribute("token_id", token_id))
     }
 
+    /// Mints like `mint` and also records when and by whom the token was minted
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint_with_info(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        extension: T,
+    ) -> Result<Response<C>, ContractError> {
+        let mint_info = MintInfo {
+            minted_at: env.block.time,
+            minted_by: info.sender.clone(),
+        };
+        let res = self.mint(
+            deps.branch(),
+            info,
+            token_id.clone(),
+            owner,
+            token_uri,
+            extension,
+        )?;
+        self.mint_info.save(deps.storage, &token_id, &mint_info)?;
+        Ok(res)
+    }
+
     pub fn update_ownership(
         deps: DepsMut,
         env: Env,
@@ -198,6 +247,163 @@ where
             None => Err(ContractError::NoWithdrawAddress {}),
         }
     }
+
+    pub fn set_transfer_policy(
+        &self,
+        deps: DepsMut,
+        sender: &Addr,
+        allowlist: Option<Vec<String>>,
+    ) -> Result<Response<C>, ContractError> {
+        cw_ownable::assert_owner(deps.storage, sender)?;
+        let policy = match allowlist {
+            Some(contracts) => TransferPolicy::Allowlist {
+                contracts: contracts
+                    .iter()
+                    .map(|addr| deps.api.addr_validate(addr))
+                    .collect::<StdResult<_>>()?,
+            },
+            None => TransferPolicy::Open,
+        };
+        self.transfer_policy.save(deps.storage, &policy)?;
+
+        let mode = match policy {
+            TransferPolicy::Open => "open",
+            TransferPolicy::Allowlist { .. } => "allowlist",
+        };
+        Ok(Response::new()
+            .add_attribute("action", "set_transfer_policy")
+            .add_attribute("policy", mode))
+    }
+
+    pub fn set_owner_history_length(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        length: u32,
+    ) -> Result<Response<C>, ContractError> {
+        cw_ownable::assert_owner(storage, sender)?;
+        if length > MAX_OWNER_HISTORY_LENGTH {
+            return Err(ContractError::OwnerHistoryTooLong {
+                max: MAX_OWNER_HISTORY_LENGTH,
+            });
+        }
+        self.owner_history_length.save(storage, &length)?;
+        Ok(Response::new()
+            .add_attribute("action", "set_owner_history_length")
+            .add_attribute("length", length.to_string()))
+    }
+
+    pub fn offer_swap(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        offered_token_id: String,
+        wanted_token_id: String,
+        expires: Option<Expiration>,
+    ) -> Result<Response<C>, ContractError> {
+        if offered_token_id == wanted_token_id {
+            return Err(ContractError::SelfSwap {});
+        }
+        // reject expired data as invalid
+        let expires = expires.unwrap_or_default();
+        if expires.is_expired(&env.block) {
+            return Err(ContractError::Expired {});
+        }
+        // ensure the wanted token exists
+        self.tokens.load(deps.storage, &wanted_token_id)?;
+
+        let mut token = self.tokens.load(deps.storage, &offered_token_id)?;
+        // ensure we have permissions
+        self.check_can_send(deps.as_ref(), &env, &info, &token)?;
+        self.check_transfer_policy(deps.storage, &info, &token)?;
+
+        let offer = SwapOffer {
+            offerer: token.owner,
+            wanted_token_id: wanted_token_id.clone(),
+            expires,
+        };
+        self.swaps.save(deps.storage, &offered_token_id, &offer)?;
+
+        // escrow the offered token until the swap is accepted or cancelled
+        token.owner = env.contract.address;
+        token.approvals = vec![];
+        self.tokens.save(deps.storage, &offered_token_id, &token)?;
+
+        Ok(Response::new()
+            .add_attribute("action", "offer_swap")
+            .add_attribute("sender", info.sender)
+            .add_attribute("offerer", offer.offerer)
+            .add_attribute("offered_token_id", offered_token_id)
+            .add_attribute("wanted_token_id", wanted_token_id))
+    }
+
+    pub fn accept_swap(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        offered_token_id: String,
+    ) -> Result<Response<C>, ContractError> {
+        let offer = self.swaps.load(deps.storage, &offered_token_id)?;
+        if offer.expires.is_expired(&env.block) {
+            return Err(ContractError::SwapExpired {
+                token_id: offered_token_id,
+            });
+        }
+
+        let mut wanted = self.tokens.load(deps.storage, &offer.wanted_token_id)?;
+        // ensure we have permissions
+        self.check_can_send(deps.as_ref(), &env, &info, &wanted)?;
+        self.check_transfer_policy(deps.storage, &info, &wanted)?;
+
+        // release the escrowed token to the owner of the wanted token, and vice versa
+        let acceptor = wanted.owner;
+        let mut offered = self.tokens.load(deps.storage, &offered_token_id)?;
+        offered.owner = acceptor.clone();
+        offered.approvals = vec![];
+        wanted.owner = offer.offerer.clone();
+        wanted.approvals = vec![];
+        self.tokens
+            .save(deps.storage, &offered_token_id, &offered)?;
+        self.tokens
+            .save(deps.storage, &offer.wanted_token_id, &wanted)?;
+        self.swaps.remove(deps.storage, &offered_token_id);
+
+        self.record_previous_owner(deps.storage, &offered_token_id, offer.offerer.clone())?;
+        self.record_previous_owner(deps.storage, &offer.wanted_token_id, acceptor.clone())?;
+
+        Ok(Response::new()
+            .add_attribute("action", "accept_swap")
+            .add_attribute("sender", info.sender)
+            .add_attribute("offerer", offer.offerer)
+            .add_attribute("acceptor", acceptor)
+            .add_attribute("offered_token_id", offered_token_id)
+            .add_attribute("wanted_token_id", offer.wanted_token_id))
+    }
+
+    pub fn cancel_swap(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        offered_token_id: String,
+    ) -> Result<Response<C>, ContractError> {
+        let offer = self.swaps.load(deps.storage, &offered_token_id)?;
+        if offer.offerer != info.sender {
+            return Err(ContractError::Ownership(OwnershipError::NotOwner));
+        }
+
+        // return the escrowed token
+        let mut token = self.tokens.load(deps.storage, &offered_token_id)?;
+        token.owner = offer.offerer;
+        self.tokens.save(deps.storage, &offered_token_id, &token)?;
+        self.swaps.remove(deps.storage, &offered_token_id);
+
+        Ok(Response::new()
+            .add_attribute("action", "cancel_swap")
+            .add_attribute("sender", info.sender)
+            .add_attribute("offered_token_id", offered_token_id))
+    }
 }
 
 impl<'a, T, C, E, Q> Cw721Execute<T, C> for Cw721Contract<'a, T, C, E, Q>
@@ -341,6 +547,8 @@ where
         self.check_can_send(deps.as_ref(), &env, &info, &token)?;
 
         self.tokens.remove(deps.storage, &token_id)?;
+        self.clear_owner_history(deps.storage, &token_id)?;
+        self.mint_info.remove(deps.storage, &token_id);
         self.decrement_tokens(deps.storage)?;
 
         Ok(Response::new()
@@ -369,10 +577,13 @@ where
         let mut token = self.tokens.load(deps.storage, token_id)?;
         // ensure we have permissions
         self.check_can_send(deps.as_ref(), env, info, &token)?;
+        self.check_transfer_policy(deps.storage, info, &token)?;
         // set owner and remove existing approvals
+        let previous_owner = token.owner;
         token.owner = deps.api.addr_validate(recipient)?;
         token.approvals = vec![];
         self.tokens.save(deps.storage, token_id, &token)?;
+        self.record_previous_owner(deps.storage, token_id, previous_owner)?;
         Ok(token)
     }
 
@@ -443,6 +654,26 @@ where
         }
     }
 
+    /// returns an error if the transfer policy forbids the sender from moving the token
+    pub fn check_transfer_policy(
+        &self,
+        storage: &dyn Storage,
+        info: &MessageInfo,
+        token: &TokenInfo<T>,
+    ) -> Result<(), ContractError> {
+        // owner can always move their own token
+        if token.owner == info.sender {
+            return Ok(());
+        }
+        if self.transfer_policy(storage)?.allows(&info.sender) {
+            Ok(())
+        } else {
+            Err(ContractError::TransferNotAllowed {
+                sender: info.sender.to_string(),
+            })
+        }
+    }
+
     /// returns true iff the sender can transfer ownership of the token
     pub fn check_can_send(
         &self,
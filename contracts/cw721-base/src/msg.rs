@@ -1,5 +1,5 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Coin};
+use cosmwasm_std::{Binary, Coin, Timestamp};
 use cw721::Expiration;
 use cw_ownable::{cw_ownable_execute, cw_ownable_query};
 use schemars::JsonSchema;
@@ -79,6 +79,27 @@ pub enum ExecuteMsg<T, E> {
     /// Withdraw from the contract to the given address. Anyone can call this,
     /// which is okay since withdraw address has been set by owner.
     WithdrawFunds { amount: Coin },
+
+    /// Restricts transfers / sends on behalf of token owners to the given contracts.
+    /// Unset removes the restriction. Only owner can call this.
+    SetTransferPolicy { allowlist: Option<Vec<String>> },
+
+    /// Sets how many previous owners are kept per token. Only owner can call this.
+    SetOwnerHistoryLength { length: u32 },
+
+    /// Offer a token in exchange for another token of this contract. The offered token
+    /// is held by the contract until the swap is accepted or cancelled.
+    /// If expiration is set, then the offer can only be accepted until then
+    OfferSwap {
+        offered_token_id: String,
+        wanted_token_id: String,
+        expires: Option<Expiration>,
+    },
+    /// Accept a swap offer, giving the wanted token to the offerer in exchange for the
+    /// offered token. Sender must be able to transfer the wanted token
+    AcceptSwap { offered_token_id: String },
+    /// Cancel a swap offer, returning the offered token to the offerer
+    CancelSwap { offered_token_id: String },
 }
 
 #[cw_ownable_query]
@@ -170,6 +191,46 @@ pub enum QueryMsg<Q: JsonSchema> {
 
     #[returns(Option<String>)]
     GetWithdrawAddress {},
+
+    /// Return whether spender could currently transfer / send the given token,
+    /// and if not, why. Error if token does not exist
+    #[returns(CanTransferResponse)]
+    CanTransfer { spender: String, token_id: String },
+
+    /// Return the policy restricting who can transfer tokens on behalf of their owners
+    #[returns(TransferPolicyResponse)]
+    TransferPolicy {},
+
+    /// Return the previous owners of the given token, most recent first
+    #[returns(OwnerHistoryResponse)]
+    OwnerHistory {
+        token_id: String,
+        limit: Option<u32>,
+    },
+    /// Return how many previous owners are kept per token
+    #[returns(OwnerHistoryLengthResponse)]
+    OwnerHistoryLength {},
+
+    /// Return when and by whom the given token was minted, error if the token does not
+    /// exist or was minted before this was recorded
+    #[returns(MintInfoResponse)]
+    MintInfo { token_id: String },
+
+    /// Return the swap offer for the given token, error if none exists
+    #[returns(SwapResponse)]
+    Swap { offered_token_id: String },
+    /// List all open swap offers
+    #[returns(SwapsResponse)]
+    Swaps {
+        /// unset or false will filter out expired offers, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Summarize the approvals of the given token that have not expired
+    #[returns(ApprovalsSummaryResponse)]
+    ApprovalsSummary { token_id: String },
 }
 
 /// Shows who can mint these tokens
@@ -177,3 +238,68 @@ pub enum QueryMsg<Q: JsonSchema> {
 pub struct MinterResponse {
     pub minter: Option<String>,
 }
+
+#[cw_serde]
+pub struct CanTransferResponse {
+    pub can_transfer: bool,
+    /// Why the transfer would be rejected, if it would be
+    pub reason: Option<CanTransferReason>,
+}
+
+#[cw_serde]
+pub enum CanTransferReason {
+    /// Spender is neither the owner of the token nor approved to transfer it
+    NotOwnerOrApproved {},
+    /// Spender is approved, but the transfer policy does not allow it to transfer
+    TransferPolicy {},
+}
+
+#[cw_serde]
+pub struct TransferPolicyResponse {
+    /// Contracts allowed to transfer/send tokens on their owners' behalf, None if any
+    /// approved spender or operator can
+    pub allowlist: Option<Vec<String>>,
+}
+
+#[cw_serde]
+pub struct SwapResponse {
+    pub offered_token_id: String,
+    /// Owner of the offered token, who receives the wanted token
+    pub offerer: String,
+    pub wanted_token_id: String,
+    pub expires: Expiration,
+}
+
+#[cw_serde]
+pub struct SwapsResponse {
+    pub swaps: Vec<SwapResponse>,
+}
+
+#[cw_serde]
+pub struct OwnerHistoryResponse {
+    /// Previous owners of the token, most recent first
+    pub owners: Vec<String>,
+}
+
+#[cw_serde]
+pub struct MintInfoResponse {
+    /// Block time at which the token was minted
+    pub minted_at: Timestamp,
+    /// Address that minted the token
+    pub minted_by: String,
+}
+
+#[cw_serde]
+pub struct OwnerHistoryLengthResponse {
+    pub length: u32,
+}
+
+#[cw_serde]
+pub struct ApprovalsSummaryResponse {
+    /// Number of approvals that have not expired
+    pub active_approvals: u32,
+    /// Soonest block height at which one of them expires, None if none expire at a height
+    pub earliest_height: Option<u64>,
+    /// Soonest time at which one of them expires, None if none expire at a time
+    pub earliest_time: Option<Timestamp>,
+}
@@ -1,5 +1,5 @@
 use cosmwasm_std::{to_json_binary, Addr, Empty, QuerierWrapper, WasmMsg};
-use cw721::OwnerOfResponse;
+use cw721::{Cw721Querier, Expiration, OwnerOfResponse};
 use cw_multi_test::{App, Contract, ContractWrapper, Executor};
 
 use crate::MinterResponse;
@@ -166,3 +166,74 @@ fn test_instantiate_016_msg() {
         .unwrap();
     assert!(withdraw_addr.is_none());
 }
+
+/// Queries a deployed contract through the typed `Cw721Querier` helper.
+#[test]
+fn test_cw721_querier() {
+    let mut app = App::default();
+    let admin = || Addr::unchecked("admin");
+
+    let code_id_latest = app.store_code(cw721_base_latest_contract());
+
+    let cw721 = app
+        .instantiate_contract(
+            code_id_latest,
+            admin(),
+            &crate::InstantiateMsg {
+                name: "collection".to_string(),
+                symbol: "symbol".to_string(),
+                minter: Some(admin().into_string()),
+                withdraw_address: None,
+            },
+            &[],
+            "cw721-base",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        admin(),
+        cw721.clone(),
+        &crate::ExecuteMsg::<Empty, Empty>::Mint {
+            token_id: "1".to_string(),
+            owner: admin().into_string(),
+            token_uri: Some("https://starships.example.com/Starship/1.json".to_string()),
+            extension: Empty::default(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        admin(),
+        cw721.clone(),
+        &crate::ExecuteMsg::<Empty, Empty>::Approve {
+            spender: "spender".to_string(),
+            token_id: "1".to_string(),
+            expires: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let querier = app.wrap();
+    let cw721_querier = Cw721Querier::new(querier, &cw721);
+
+    let owner = cw721_querier.owner_of("1", false).unwrap();
+    assert_eq!(owner.owner, admin().to_string());
+    assert_eq!(owner.approvals.len(), 1);
+
+    let approval = cw721_querier.approval("1", "spender", false).unwrap();
+    assert_eq!(approval.approval.spender, "spender");
+    assert_eq!(approval.approval.expires, Expiration::Never {});
+
+    assert_eq!(cw721_querier.num_tokens().unwrap(), 1);
+
+    let info = cw721_querier.nft_info::<Empty>("1").unwrap();
+    assert_eq!(
+        info.token_uri,
+        Some("https://starships.example.com/Starship/1.json".to_string())
+    );
+
+    // errors from the contract are passed through
+    cw721_querier.owner_of("2", false).unwrap_err();
+}
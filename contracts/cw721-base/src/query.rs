@@ -2,7 +2,8 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, BlockInfo, CustomMsg, Deps, Env, Order, StdError, StdResult,
+    to_json_binary, Addr, Binary, BlockInfo, CustomMsg, Deps, Env, MessageInfo, Order, StdError,
+    StdResult,
 };
 
 use cw721::{
@@ -10,11 +11,17 @@ use cw721::{
     Expiration, NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse,
     OwnerOfResponse, TokensResponse,
 };
+use cw_ownable::OwnershipError;
 use cw_storage_plus::Bound;
 use cw_utils::maybe_addr;
 
-use crate::msg::{MinterResponse, QueryMsg};
-use crate::state::{Approval, Cw721Contract, TokenInfo};
+use crate::error::ContractError;
+use crate::msg::{
+    ApprovalsSummaryResponse, CanTransferReason, CanTransferResponse, MintInfoResponse,
+    MinterResponse, OwnerHistoryLengthResponse, OwnerHistoryResponse, QueryMsg, SwapResponse,
+    SwapsResponse, TransferPolicyResponse,
+};
+use crate::state::{Approval, Cw721Contract, SwapOffer, TokenInfo, TransferPolicy};
 
 const DEFAULT_LIMIT: u32 = 10;
 const MAX_LIMIT: u32 = 1000;
@@ -329,6 +336,36 @@ where
             QueryMsg::GetWithdrawAddress {} => {
                 to_json_binary(&self.withdraw_address.may_load(deps.storage)?)
             }
+            QueryMsg::CanTransfer { spender, token_id } => {
+                to_json_binary(&self.can_transfer(deps, env, spender, token_id)?)
+            }
+            QueryMsg::TransferPolicy {} => to_json_binary(&TransferPolicyResponse::from(
+                self.transfer_policy(deps.storage)?,
+            )),
+            QueryMsg::OwnerHistory { token_id, limit } => {
+                to_json_binary(&self.owner_history(deps, token_id, limit)?)
+            }
+            QueryMsg::OwnerHistoryLength {} => to_json_binary(&OwnerHistoryLengthResponse {
+                length: self.owner_history_length(deps.storage)?,
+            }),
+            QueryMsg::MintInfo { token_id } => to_json_binary(&self.mint_info(deps, token_id)?),
+            QueryMsg::Swap { offered_token_id } => {
+                to_json_binary(&self.swap(deps, offered_token_id)?)
+            }
+            QueryMsg::Swaps {
+                include_expired,
+                start_after,
+                limit,
+            } => to_json_binary(&self.swaps(
+                deps,
+                env,
+                include_expired.unwrap_or(false),
+                start_after,
+                limit,
+            )?),
+            QueryMsg::ApprovalsSummary { token_id } => {
+                to_json_binary(&self.approvals_summary(deps, env, token_id)?)
+            }
         }
     }
 
@@ -343,6 +380,124 @@ where
     pub fn ownership(deps: Deps) -> StdResult<cw_ownable::Ownership<Addr>> {
         cw_ownable::get_ownership(deps.storage)
     }
+
+    /// runs the same permission and transfer policy checks as TransferNft
+    pub fn can_transfer(
+        &self,
+        deps: Deps,
+        env: Env,
+        spender: String,
+        token_id: String,
+    ) -> StdResult<CanTransferResponse> {
+        let token = self.tokens.load(deps.storage, &token_id)?;
+        let info = MessageInfo {
+            sender: deps.api.addr_validate(&spender)?,
+            funds: vec![],
+        };
+
+        let reason = match self
+            .check_can_send(deps, &env, &info, &token)
+            .and_then(|_| self.check_transfer_policy(deps.storage, &info, &token))
+        {
+            Ok(()) => None,
+            Err(ContractError::Ownership(OwnershipError::NotOwner)) => {
+                Some(CanTransferReason::NotOwnerOrApproved {})
+            }
+            Err(ContractError::TransferNotAllowed { .. }) => {
+                Some(CanTransferReason::TransferPolicy {})
+            }
+            Err(ContractError::Std(err)) => return Err(err),
+            Err(err) => return Err(StdError::generic_err(err.to_string())),
+        };
+        Ok(CanTransferResponse {
+            can_transfer: reason.is_none(),
+            reason,
+        })
+    }
+
+    pub fn mint_info(&self, deps: Deps, token_id: String) -> StdResult<MintInfoResponse> {
+        // error if token does not exist
+        self.tokens.load(deps.storage, &token_id)?;
+
+        let mint_info = self.mint_info.load(deps.storage, &token_id)?;
+        Ok(MintInfoResponse {
+            minted_at: mint_info.minted_at,
+            minted_by: mint_info.minted_by.into_string(),
+        })
+    }
+
+    pub fn owner_history(
+        &self,
+        deps: Deps,
+        token_id: String,
+        limit: Option<u32>,
+    ) -> StdResult<OwnerHistoryResponse> {
+        // error if token does not exist
+        self.tokens.load(deps.storage, &token_id)?;
+
+        let length = self.owner_history_length(deps.storage)?;
+        let limit = limit.unwrap_or(length).min(length);
+        let owners = self
+            .previous_owners(deps.storage, &token_id, limit)?
+            .into_iter()
+            .map(Addr::into_string)
+            .collect();
+
+        Ok(OwnerHistoryResponse { owners })
+    }
+
+    pub fn swap(&self, deps: Deps, offered_token_id: String) -> StdResult<SwapResponse> {
+        let offer = self.swaps.load(deps.storage, &offered_token_id)?;
+        Ok(humanize_swap(offered_token_id, offer))
+    }
+
+    pub fn swaps(
+        &self,
+        deps: Deps,
+        env: Env,
+        include_expired: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<SwapsResponse> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+        let start = start_after.map(|s| Bound::ExclusiveRaw(s.into()));
+
+        let swaps = self
+            .swaps
+            .range(deps.storage, start, None, Order::Ascending)
+            .filter(|r| {
+                r.as_ref().map_or(true, |(_, o)| {
+                    include_expired || !o.expires.is_expired(&env.block)
+                })
+            })
+            .take(limit)
+            .map(|item| item.map(|(k, offer)| humanize_swap(k, offer)))
+            .collect::<StdResult<_>>()?;
+
+        Ok(SwapsResponse { swaps })
+    }
+
+    pub fn approvals_summary(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+    ) -> StdResult<ApprovalsSummaryResponse> {
+        let token = self.tokens.load(deps.storage, &token_id)?;
+        Ok(summarize_approvals(&env.block, &token.approvals))
+    }
+}
+
+impl From<TransferPolicy> for TransferPolicyResponse {
+    fn from(policy: TransferPolicy) -> Self {
+        let allowlist = match policy {
+            TransferPolicy::Open => None,
+            TransferPolicy::Allowlist { contracts } => {
+                Some(contracts.into_iter().map(Addr::into_string).collect())
+            }
+        };
+        TransferPolicyResponse { allowlist }
+    }
 }
 
 fn parse_approval(item: StdResult<(Addr, Expiration)>) -> StdResult<cw721::Approval> {
@@ -352,6 +507,41 @@ fn parse_approval(item: StdResult<(Addr, Expiration)>) -> StdResult<cw721::Appro
     })
 }
 
+fn humanize_swap(offered_token_id: String, offer: SwapOffer) -> SwapResponse {
+    SwapResponse {
+        offered_token_id,
+        offerer: offer.offerer.into_string(),
+        wanted_token_id: offer.wanted_token_id,
+        expires: offer.expires,
+    }
+}
+
+/// Counts the approvals that have not expired and finds the soonest height and time at which
+/// one of them expires. Height and time based expirations cannot be compared, so each is
+/// tracked on its own; approvals that never expire only add to the count.
+fn summarize_approvals(block: &BlockInfo, approvals: &[Approval]) -> ApprovalsSummaryResponse {
+    approvals.iter().filter(|apr| !apr.is_expired(block)).fold(
+        ApprovalsSummaryResponse {
+            active_approvals: 0,
+            earliest_height: None,
+            earliest_time: None,
+        },
+        |mut summary, apr| {
+            summary.active_approvals += 1;
+            match apr.expires {
+                Expiration::AtHeight(h) => {
+                    summary.earliest_height = Some(summary.earliest_height.map_or(h, |e| e.min(h)))
+                }
+                Expiration::AtTime(t) => {
+                    summary.earliest_time = Some(summary.earliest_time.map_or(t, |e| e.min(t)))
+                }
+                Expiration::Never {} => {}
+            }
+            summary
+        },
+    )
+}
+
 fn humanize_approvals<T>(
     block: &BlockInfo,
     info: &TokenInfo<T>,
@@ -1,13 +1,17 @@
+use cosmwasm_schema::cw_serde;
 use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
 
-use cosmwasm_std::{Addr, BlockInfo, CustomMsg, StdResult, Storage};
+use cosmwasm_std::{Addr, BlockInfo, CustomMsg, StdResult, Storage, Timestamp};
 
 use cw721::{ContractInfoResponse, Cw721, Expiration};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
+pub const DEFAULT_OWNER_HISTORY_LENGTH: u32 = 10;
+pub const MAX_OWNER_HISTORY_LENGTH: u32 = 100;
+
 pub struct Cw721Contract<'a, T, C, E, Q>
 where
     T: Serialize + DeserializeOwned + Clone,
@@ -20,6 +24,16 @@ where
     pub operators: Map<'a, (&'a Addr, &'a Addr), Expiration>,
     pub tokens: IndexedMap<'a, &'a str, TokenInfo<T>, TokenIndexes<'a, T>>,
     pub withdraw_address: Item<'a, String>,
+    pub transfer_policy: Item<'a, TransferPolicy>,
+    /// Previous owners of each token, stored as a ring buffer of `owner_history_length` slots
+    pub owner_history: Map<'a, (&'a str, u32), Addr>,
+    pub owner_history_heads: Map<'a, &'a str, OwnerHistoryHead>,
+    pub owner_history_length: Item<'a, u32>,
+    /// When and by whom each token was minted, kept apart from `TokenInfo` so it works for
+    /// any extension
+    pub mint_info: Map<'a, &'a str, MintInfo>,
+    /// Open swap offers, keyed by the offered (escrowed) token id
+    pub swaps: Map<'a, &'a str, SwapOffer>,
 
     pub(crate) _custom_response: PhantomData<C>,
     pub(crate) _custom_query: PhantomData<Q>,
@@ -50,6 +64,12 @@ where
             "tokens",
             "tokens__owner",
             "withdraw_address",
+            "transfer_policy",
+            "owner_history",
+            "owner_history_heads",
+            "owner_history_length",
+            "mint_info",
+            "swaps",
         )
     }
 }
@@ -60,6 +80,7 @@ where
     E: CustomMsg,
     Q: CustomMsg,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         contract_key: &'a str,
         token_count_key: &'a str,
@@ -67,6 +88,12 @@ where
         tokens_key: &'a str,
         tokens_owner_key: &'a str,
         withdraw_address_key: &'a str,
+        transfer_policy_key: &'a str,
+        owner_history_key: &'a str,
+        owner_history_heads_key: &'a str,
+        owner_history_length_key: &'a str,
+        mint_info_key: &'a str,
+        swaps_key: &'a str,
     ) -> Self {
         let indexes = TokenIndexes {
             owner: MultiIndex::new(token_owner_idx, tokens_key, tokens_owner_key),
@@ -77,6 +104,12 @@ where
             operators: Map::new(operator_key),
             tokens: IndexedMap::new(tokens_key, indexes),
             withdraw_address: Item::new(withdraw_address_key),
+            transfer_policy: Item::new(transfer_policy_key),
+            owner_history: Map::new(owner_history_key),
+            owner_history_heads: Map::new(owner_history_heads_key),
+            owner_history_length: Item::new(owner_history_length_key),
+            mint_info: Map::new(mint_info_key),
+            swaps: Map::new(swaps_key),
             _custom_response: PhantomData,
             _custom_execute: PhantomData,
             _custom_query: PhantomData,
@@ -98,6 +131,102 @@ where
         self.token_count.save(storage, &val)?;
         Ok(val)
     }
+
+    pub fn transfer_policy(&self, storage: &dyn Storage) -> StdResult<TransferPolicy> {
+        Ok(self.transfer_policy.may_load(storage)?.unwrap_or_default())
+    }
+
+    pub fn owner_history_length(&self, storage: &dyn Storage) -> StdResult<u32> {
+        Ok(self
+            .owner_history_length
+            .may_load(storage)?
+            .unwrap_or(DEFAULT_OWNER_HISTORY_LENGTH))
+    }
+
+    /// Returns up to `limit` previous owners of the token, most recent first
+    pub fn previous_owners(
+        &self,
+        storage: &dyn Storage,
+        token_id: &str,
+        limit: u32,
+    ) -> StdResult<Vec<Addr>> {
+        let head = match self.owner_history_heads.may_load(storage, token_id)? {
+            Some(head) => head,
+            None => return Ok(vec![]),
+        };
+        (1..=head.filled.min(limit))
+            .map(|i| {
+                let slot = (head.next + head.length - i) % head.length;
+                self.owner_history.load(storage, (token_id, slot))
+            })
+            .collect()
+    }
+
+    /// Writes `owner` into the next slot of the token's owner history, overwriting the oldest
+    /// entry once the buffer is full
+    pub fn record_previous_owner(
+        &self,
+        storage: &mut dyn Storage,
+        token_id: &str,
+        owner: Addr,
+    ) -> StdResult<()> {
+        let length = self.owner_history_length(storage)?;
+        let mut head = match self.owner_history_heads.may_load(storage, token_id)? {
+            Some(head) if head.length == length => head,
+            // the length was changed since this token was last recorded, lay the kept
+            // owners out again in a buffer of the new length
+            Some(_) => {
+                let kept = self.previous_owners(storage, token_id, length)?;
+                self.clear_owner_history(storage, token_id)?;
+                if length == 0 {
+                    return Ok(());
+                }
+                for (slot, owner) in kept.iter().rev().enumerate() {
+                    self.owner_history
+                        .save(storage, (token_id, slot as u32), owner)?;
+                }
+                OwnerHistoryHead {
+                    next: kept.len() as u32 % length,
+                    filled: kept.len() as u32,
+                    length,
+                }
+            }
+            None if length == 0 => return Ok(()),
+            None => OwnerHistoryHead {
+                next: 0,
+                filled: 0,
+                length,
+            },
+        };
+
+        self.owner_history
+            .save(storage, (token_id, head.next), &owner)?;
+        head.next = (head.next + 1) % length;
+        head.filled = (head.filled + 1).min(length);
+        self.owner_history_heads.save(storage, token_id, &head)
+    }
+
+    /// Removes all recorded previous owners of the token
+    pub fn clear_owner_history(&self, storage: &mut dyn Storage, token_id: &str) -> StdResult<()> {
+        if let Some(head) = self.owner_history_heads.may_load(storage, token_id)? {
+            for slot in 0..head.filled {
+                self.owner_history.remove(storage, (token_id, slot));
+            }
+            self.owner_history_heads.remove(storage, token_id);
+        }
+        Ok(())
+    }
+}
+
+/// Position in a token's owner history ring buffer
+#[cw_serde]
+pub struct OwnerHistoryHead {
+    /// Slot the next previous owner is written to
+    pub next: u32,
+    /// Number of slots in use, at most `length`
+    pub filled: u32,
+    /// Number of slots the buffer was laid out with
+    pub length: u32,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -116,6 +245,25 @@ pub struct TokenInfo<T> {
     pub extension: T,
 }
 
+#[cw_serde]
+pub struct MintInfo {
+    /// Block time at which the token was minted
+    pub minted_at: Timestamp,
+    /// Address that minted the token
+    pub minted_by: Addr,
+}
+
+/// An offer to trade an escrowed token for another token of this contract
+#[cw_serde]
+pub struct SwapOffer {
+    /// Owner of the offered token, who receives the wanted token
+    pub offerer: Addr,
+    /// Token the offerer wants in return
+    pub wanted_token_id: String,
+    /// When the offer can no longer be accepted (maybe Expiration::never)
+    pub expires: Expiration,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
 pub struct Approval {
     /// Account that can transfer/send the token
@@ -130,6 +278,27 @@ impl Approval {
     }
 }
 
+/// Controls who, besides the token owner, may move a token
+#[cw_serde]
+#[derive(Default)]
+pub enum TransferPolicy {
+    /// Any approved spender or operator can transfer/send the token
+    #[default]
+    Open,
+    /// Only the listed contracts (e.g. royalty-respecting marketplaces) can transfer/send
+    /// the token on the owner's behalf. The owner can always move their own token.
+    Allowlist { contracts: Vec<Addr> },
+}
+
+impl TransferPolicy {
+    pub fn allows(&self, sender: &Addr) -> bool {
+        match self {
+            TransferPolicy::Open => true,
+            TransferPolicy::Allowlist { contracts } => contracts.contains(sender),
+        }
+    }
+}
+
 pub struct TokenIndexes<'a, T>
 where
     T: Serialize + DeserializeOwned + Clone,
@@ -17,6 +17,10 @@ use crate::{
     error::ContractError, msg::ExecuteMsg, msg::InstantiateMsg, msg::QueryMsg, Extension,
     MinterResponse,
 };
+use cw721_base::msg::{
+    ApprovalsSummaryResponse, CanTransferReason, CanTransferResponse, MintInfoResponse,
+    OwnerHistoryLengthResponse, SwapResponse, SwapsResponse, TransferPolicyResponse,
+};
 use cw721_base::ContractError as Cw721ContractError;
 
 const MINTER: &str = "merlin";
@@ -144,12 +148,14 @@ fn test_mint() {
         }
     );
 
-    // assert mint timestamp is set
+    // assert mint timestamp is taken from the base contract's mint info
     let mint_timestamp = contract
-        .mint_timestamps
-        .load(deps.as_ref().storage, token_id.as_str())
+        .mint_date(deps.as_ref().storage, token_id.as_str())
         .unwrap();
     assert_eq!(mint_timestamp, mock_env().block.time);
+    assert!(!contract
+        .mint_timestamps
+        .has(deps.as_ref().storage, token_id.as_str()));
 
     // Cannot mint same token_id again
     let mint_msg2 = ExecuteMsg::Mint {
@@ -316,6 +322,10 @@ fn test_burn() {
     let _ = contract
         .execute(deps.as_mut(), env.clone(), minter.clone(), burn_msg.clone())
         .unwrap();
+    assert!(!contract
+        .base_contract
+        .mint_info
+        .has(deps.as_ref().storage, token_id.as_str()));
 
     // ensure num tokens decreases
     let count = contract.num_tokens(deps.as_ref()).unwrap();
@@ -1407,3 +1417,471 @@ fn test_all_tokens() {
         }
     );
 }
+
+#[test]
+fn test_transfer_policy() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+
+    let query_policy = |deps: cosmwasm_std::Deps| -> TransferPolicyResponse {
+        let res = contract
+            .query(deps, mock_env(), QueryMsg::TransferPolicy {})
+            .unwrap();
+        from_json(res).unwrap()
+    };
+    assert_eq!(query_policy(deps.as_ref()).allowlist, None);
+
+    contract
+        .execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(MINTER, &[]),
+            ExecuteMsg::SetTransferPolicy {
+                allowlist: Some(vec![String::from("market")]),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        query_policy(deps.as_ref()).allowlist,
+        Some(vec![String::from("market")])
+    );
+}
+
+#[test]
+fn test_owner_history() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    let token_id = "grow1".to_string();
+    let mut env = mock_env();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("ark"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg)
+        .unwrap();
+    let transfer_msg = ExecuteMsg::TransferNft {
+        recipient: String::from("noah"),
+        token_id: token_id.clone(),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ark", &[]),
+            transfer_msg,
+        )
+        .unwrap();
+
+    // the default length is kept until changed
+    let res: OwnerHistoryLengthResponse = from_json(
+        contract
+            .query(deps.as_ref(), env.clone(), QueryMsg::OwnerHistoryLength {})
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.length, 10);
+
+    // assert valid nft is returned
+    let history = contract
+        .owner_history(deps.as_ref(), env.clone(), token_id.clone(), None, false)
+        .unwrap();
+    assert_eq!(history.owners, vec!["ark"]);
+
+    // assert invalid nft throws error
+    let mint_date = env.block.time;
+    let expiration = env.block.time.plus_days(1);
+    env.block.time = expiration;
+    let error = contract
+        .owner_history(deps.as_ref(), env.clone(), token_id.clone(), None, false)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id: token_id.clone(),
+            mint_date,
+            expiration
+        }
+    );
+
+    // unless invalid nfts are included
+    let history = contract
+        .owner_history(deps.as_ref(), env, token_id, None, true)
+        .unwrap();
+    assert_eq!(history.owners, vec!["ark"]);
+}
+
+#[test]
+fn test_legacy_mint_timestamps() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    let token_id = "grow1".to_string();
+    let mut env = mock_env();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("ark"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg)
+        .unwrap();
+
+    // tokens minted by an older version only have a mint timestamp in this contract
+    let mint_date = env.block.time.minus_days(1);
+    contract
+        .base_contract
+        .mint_info
+        .remove(deps.as_mut().storage, &token_id);
+    contract
+        .mint_timestamps
+        .save(deps.as_mut().storage, &token_id, &mint_date)
+        .unwrap();
+
+    let error = contract
+        .owner_of(deps.as_ref(), env.clone(), token_id.clone(), false, false)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id: token_id.clone(),
+            mint_date,
+            expiration: env.block.time
+        }
+    );
+
+    // the legacy entry is removed on burn
+    env.block.time = mint_date;
+    contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info("ark", &[]),
+            ExecuteMsg::Burn {
+                token_id: token_id.clone(),
+            },
+        )
+        .unwrap();
+    assert!(!contract
+        .mint_timestamps
+        .has(deps.as_ref().storage, token_id.as_str()));
+}
+
+#[test]
+fn test_mint_info() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    let token_id = "grow1".to_string();
+    let mut env = mock_env();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("ark"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg)
+        .unwrap();
+
+    // assert valid nft is returned
+    let mint_info = contract
+        .mint_info(deps.as_ref(), env.clone(), token_id.clone(), false)
+        .unwrap();
+    assert_eq!(
+        mint_info,
+        MintInfoResponse {
+            minted_at: env.block.time,
+            minted_by: MINTER.to_string(),
+        }
+    );
+
+    // assert invalid nft throws error
+    let mint_date = env.block.time;
+    let expiration = env.block.time.plus_days(1);
+    env.block.time = expiration;
+    let error = contract
+        .mint_info(deps.as_ref(), env, token_id.clone(), false)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id,
+            mint_date,
+            expiration
+        }
+    );
+}
+
+#[test]
+fn test_approvals_summary() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    let token_id = "grow1".to_string();
+    let mut env = mock_env();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("ark"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg)
+        .unwrap();
+    let approve_msg = ExecuteMsg::Approve {
+        spender: String::from("noah"),
+        token_id: token_id.clone(),
+        expires: Some(Expiration::AtHeight(env.block.height + 10)),
+    };
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ark", &[]),
+            approve_msg,
+        )
+        .unwrap();
+
+    // assert valid nft is returned
+    let summary = contract
+        .approvals_summary(deps.as_ref(), env.clone(), token_id.clone(), false)
+        .unwrap();
+    assert_eq!(
+        summary,
+        ApprovalsSummaryResponse {
+            active_approvals: 1,
+            earliest_height: Some(env.block.height + 10),
+            earliest_time: None,
+        }
+    );
+
+    // assert invalid nft throws error
+    let mint_date = env.block.time;
+    let expiration = env.block.time.plus_days(1);
+    env.block.time = expiration;
+    let error = contract
+        .approvals_summary(deps.as_ref(), env, token_id.clone(), false)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id,
+            mint_date,
+            expiration
+        }
+    );
+}
+
+#[test]
+fn test_swap() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    // "grow1" expires half a day before "grow2"
+    let mut env = mock_env();
+    let expiration = env.block.time.plus_days(1);
+    for (token_id, owner) in [("grow1", "ark"), ("grow2", "noah")] {
+        contract
+            .execute(
+                deps.as_mut(),
+                env.clone(),
+                minter.clone(),
+                ExecuteMsg::Mint {
+                    token_id: String::from(token_id),
+                    owner: String::from(owner),
+                    token_uri: None,
+                    extension: None,
+                },
+            )
+            .unwrap();
+        env.block.time = env.block.time.plus_hours(12);
+    }
+
+    let offer_msg = |offered: &str, wanted: &str| ExecuteMsg::OfferSwap {
+        offered_token_id: String::from(offered),
+        wanted_token_id: String::from(wanted),
+        expires: None,
+    };
+    let accept_msg = |offered: &str| ExecuteMsg::AcceptSwap {
+        offered_token_id: String::from(offered),
+    };
+
+    // offered token expires before the swap is accepted
+    env.block.time = mock_env().block.time.plus_hours(13);
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ark", &[]),
+            offer_msg("grow1", "grow2"),
+        )
+        .unwrap();
+    let swap: SwapResponse = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Swap {
+                    offered_token_id: String::from("grow1"),
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(swap.offerer, "ark");
+    assert_eq!(swap.wanted_token_id, "grow2");
+    let swaps: SwapsResponse = from_json(
+        contract
+            .query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Swaps {
+                    include_expired: None,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(swaps.swaps, vec![swap]);
+
+    env.block.time = expiration;
+    let error = contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("noah", &[]),
+            accept_msg("grow1"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id: String::from("grow1"),
+            mint_date: mock_env().block.time,
+            expiration,
+        }
+    );
+
+    // the expired escrowed token can still be returned to its owner
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("ark", &[]),
+            ExecuteMsg::CancelSwap {
+                offered_token_id: String::from("grow1"),
+            },
+        )
+        .unwrap();
+    let owner = contract
+        .owner_of(
+            deps.as_ref(),
+            env.clone(),
+            String::from("grow1"),
+            false,
+            true,
+        )
+        .unwrap();
+    assert_eq!(owner.owner, "ark");
+
+    // wanted token expires before the swap is accepted
+    env.block.time = mock_env().block.time.plus_hours(13);
+    contract
+        .execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("noah", &[]),
+            offer_msg("grow2", "grow1"),
+        )
+        .unwrap();
+    env.block.time = expiration;
+    let error = contract
+        .execute(
+            deps.as_mut(),
+            env,
+            mock_info("ark", &[]),
+            accept_msg("grow2"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id: String::from("grow1"),
+            mint_date: mock_env().block.time,
+            expiration,
+        }
+    );
+}
+
+#[test]
+fn test_can_transfer() {
+    let mut deps = mock_dependencies();
+    let contract = setup_contract(deps.as_mut(), 1);
+    let minter = mock_info(MINTER, &[]);
+
+    let token_id = "grow1".to_string();
+    let mut env = mock_env();
+    let mint_msg = ExecuteMsg::Mint {
+        token_id: token_id.clone(),
+        owner: String::from("ark"),
+        token_uri: None,
+        extension: None,
+    };
+    contract
+        .execute(deps.as_mut(), env.clone(), minter, mint_msg)
+        .unwrap();
+
+    // valid nfts are checked by the base contract
+    let res = contract
+        .can_transfer(
+            deps.as_ref(),
+            env.clone(),
+            String::from("random"),
+            token_id.clone(),
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        CanTransferResponse {
+            can_transfer: false,
+            reason: Some(CanTransferReason::NotOwnerOrApproved {}),
+        }
+    );
+    let res = contract
+        .can_transfer(
+            deps.as_ref(),
+            env.clone(),
+            String::from("ark"),
+            token_id.clone(),
+        )
+        .unwrap();
+    assert!(res.can_transfer);
+
+    // assert invalid nft throws error
+    let mint_date = env.block.time;
+    let expiration = env.block.time.plus_days(1);
+    env.block.time = expiration;
+    let error = contract
+        .can_transfer(deps.as_ref(), env, String::from("ark"), token_id.clone())
+        .unwrap_err();
+    assert_eq!(
+        error,
+        ContractError::NftExpired {
+            token_id,
+            mint_date,
+            expiration
+        }
+    );
+}
@@ -1,5 +1,6 @@
 use cosmwasm_std::{
     Addr, Binary, Coin, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult, Storage,
+    Timestamp,
 };
 use cw721::{Cw721Execute, Expiration};
 use cw721_base::Cw721Contract;
@@ -81,6 +82,23 @@ impl<'a> Cw721ExpirationContract<'a> {
                 self.remove_withdraw_address(deps.storage, &info.sender)
             }
             ExecuteMsg::WithdrawFunds { amount } => self.withdraw_funds(deps.storage, &amount),
+            ExecuteMsg::SetTransferPolicy { allowlist } => {
+                self.set_transfer_policy(deps, &info.sender, allowlist)
+            }
+            ExecuteMsg::SetOwnerHistoryLength { length } => {
+                self.set_owner_history_length(deps.storage, &info.sender, length)
+            }
+            ExecuteMsg::OfferSwap {
+                offered_token_id,
+                wanted_token_id,
+                expires,
+            } => self.offer_swap(deps, env, info, offered_token_id, wanted_token_id, expires),
+            ExecuteMsg::AcceptSwap { offered_token_id } => {
+                self.accept_swap(deps, env, info, offered_token_id)
+            }
+            ExecuteMsg::CancelSwap { offered_token_id } => {
+                self.cancel_swap(deps, info, offered_token_id)
+            }
         }
     }
 }
@@ -98,11 +116,9 @@ impl<'a> Cw721ExpirationContract<'a> {
         extension: Extension,
     ) -> Result<Response, ContractError> {
         let mint_timstamp = env.block.time;
-        self.mint_timestamps
-            .save(deps.storage, &token_id, &mint_timstamp)?;
         let res = self
             .base_contract
-            .mint(deps, info, token_id, owner, token_uri, extension)?
+            .mint_with_info(deps, env, info, token_id, owner, token_uri, extension)?
             .add_attribute("mint_timestamp", mint_timstamp.to_string());
         Ok(res)
     }
@@ -148,6 +164,28 @@ impl<'a> Cw721ExpirationContract<'a> {
     ) -> Result<Response, ContractError> {
         Ok(self.base_contract.withdraw_funds(storage, amount)?)
     }
+
+    pub fn set_transfer_policy(
+        &self,
+        deps: DepsMut,
+        sender: &Addr,
+        allowlist: Option<Vec<String>>,
+    ) -> Result<Response, ContractError> {
+        Ok(self
+            .base_contract
+            .set_transfer_policy(deps, sender, allowlist)?)
+    }
+
+    pub fn set_owner_history_length(
+        &self,
+        storage: &mut dyn Storage,
+        sender: &Addr,
+        length: u32,
+    ) -> Result<Response, ContractError> {
+        Ok(self
+            .base_contract
+            .set_owner_history_length(storage, sender, length)?)
+    }
 }
 
 // execute
@@ -241,16 +279,76 @@ impl<'a> Cw721ExpirationContract<'a> {
         token_id: String,
     ) -> Result<Response, ContractError> {
         self.assert_valid_nft(deps.as_ref(), &env, &token_id)?;
+        self.mint_timestamps.remove(deps.storage, &token_id);
         Ok(self.base_contract.burn(deps, env, info, token_id)?)
     }
+
+    fn offer_swap(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        offered_token_id: String,
+        wanted_token_id: String,
+        expires: Option<Expiration>,
+    ) -> Result<Response, ContractError> {
+        self.assert_valid_nft(deps.as_ref(), &env, &offered_token_id)?;
+        self.assert_valid_nft(deps.as_ref(), &env, &wanted_token_id)?;
+        Ok(self.base_contract.offer_swap(
+            deps,
+            env,
+            info,
+            offered_token_id,
+            wanted_token_id,
+            expires,
+        )?)
+    }
+
+    fn accept_swap(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        offered_token_id: String,
+    ) -> Result<Response, ContractError> {
+        let offer = self
+            .base_contract
+            .swaps
+            .load(deps.storage, &offered_token_id)?;
+        self.assert_valid_nft(deps.as_ref(), &env, &offered_token_id)?;
+        self.assert_valid_nft(deps.as_ref(), &env, &offer.wanted_token_id)?;
+        Ok(self
+            .base_contract
+            .accept_swap(deps, env, info, offered_token_id)?)
+    }
+
+    fn cancel_swap(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        offered_token_id: String,
+    ) -> Result<Response, ContractError> {
+        Ok(self
+            .base_contract
+            .cancel_swap(deps, info, offered_token_id)?)
+    }
 }
 
 // helpers
 impl<'a> Cw721ExpirationContract<'a> {
+    /// mint time recorded by the base contract, or by this contract for tokens minted before
+    /// the base contract tracked it
+    pub fn mint_date(&self, storage: &dyn Storage, token_id: &str) -> StdResult<Timestamp> {
+        match self.base_contract.mint_info.may_load(storage, token_id)? {
+            Some(mint_info) => Ok(mint_info.minted_at),
+            None => self.mint_timestamps.load(storage, token_id),
+        }
+    }
+
     /// throws contract error if nft is expired
     pub fn is_valid_nft(&self, deps: Deps, env: &Env, token_id: &str) -> StdResult<bool> {
         // any non-expired token approval can send
-        let mint_date = self.mint_timestamps.load(deps.storage, token_id)?;
+        let mint_date = self.mint_date(deps.storage, token_id)?;
         let expiration_days = self.expiration_days.load(deps.storage)?;
         let expiration = mint_date.plus_days(expiration_days.into());
         if env.block.time >= expiration {
@@ -267,7 +365,7 @@ impl<'a> Cw721ExpirationContract<'a> {
         token_id: &str,
     ) -> Result<(), ContractError> {
         // any non-expired token approval can send
-        let mint_date = self.mint_timestamps.load(deps.storage, token_id)?;
+        let mint_date = self.mint_date(deps.storage, token_id)?;
         let expiration_days = self.expiration_days.load(deps.storage)?;
         let expiration = mint_date.plus_days(expiration_days.into());
         if env.block.time >= expiration {
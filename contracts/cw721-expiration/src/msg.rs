@@ -122,6 +122,53 @@ pub enum QueryMsg {
     #[returns(MinterResponse)]
     Minter {},
 
+    /// Return whether spender could currently transfer / send the given token,
+    /// and if not, why. Error if token does not exist or has expired
+    #[returns(cw721_base::msg::CanTransferResponse)]
+    CanTransfer { spender: String, token_id: String },
+
+    /// Returns the transfer policy applied to approved spenders and operators
+    #[returns(cw721_base::msg::TransferPolicyResponse)]
+    TransferPolicy {},
+
+    /// Return the swap offer for the given token, error if none exists
+    #[returns(cw721_base::msg::SwapResponse)]
+    Swap { offered_token_id: String },
+    /// List all open swap offers
+    #[returns(cw721_base::msg::SwapsResponse)]
+    Swaps {
+        /// unset or false will filter out expired offers, you must set to true to see them
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Returns the previous owners of a token, most recent first
+    #[returns(cw721_base::msg::OwnerHistoryResponse)]
+    OwnerHistory {
+        token_id: String,
+        limit: Option<u32>,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_invalid: Option<bool>,
+    },
+    /// Returns how many previous owners are kept per token
+    #[returns(cw721_base::msg::OwnerHistoryLengthResponse)]
+    OwnerHistoryLength {},
+    /// Returns when and by whom the given token was minted
+    #[returns(cw721_base::msg::MintInfoResponse)]
+    MintInfo {
+        token_id: String,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_invalid: Option<bool>,
+    },
+    /// Summarizes the approvals of the given token that have not expired
+    #[returns(cw721_base::msg::ApprovalsSummaryResponse)]
+    ApprovalsSummary {
+        token_id: String,
+        /// unset or false will filter out expired nfts, you must set to true to see them
+        include_invalid: Option<bool>,
+    },
+
     /// Extension query
     #[returns(())]
     Extension { msg: Empty },
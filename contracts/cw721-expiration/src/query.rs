@@ -4,7 +4,14 @@ use cw721::{
     NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse, OwnerOfResponse,
     TokensResponse,
 };
-use cw721_base::MinterResponse;
+use cw721_base::{
+    msg::{
+        ApprovalsSummaryResponse, CanTransferResponse, MintInfoResponse,
+        OwnerHistoryLengthResponse, OwnerHistoryResponse, SwapResponse, SwapsResponse,
+        TransferPolicyResponse,
+    },
+    MinterResponse,
+};
 
 use crate::{error::ContractError, msg::QueryMsg, state::Cw721ExpirationContract, Extension};
 
@@ -118,6 +125,56 @@ impl<'a> Cw721ExpirationContract<'a> {
                 include_invalid.unwrap_or(false),
             )?)?),
             QueryMsg::Ownership {} => Ok(to_json_binary(&Self::ownership(deps)?)?),
+            QueryMsg::CanTransfer { spender, token_id } => Ok(to_json_binary(
+                &self.can_transfer(deps, env, spender, token_id)?,
+            )?),
+            QueryMsg::TransferPolicy {} => Ok(to_json_binary(&self.transfer_policy(deps)?)?),
+            QueryMsg::Swap { offered_token_id } => {
+                Ok(to_json_binary(&self.swap(deps, offered_token_id)?)?)
+            }
+            QueryMsg::Swaps {
+                include_expired,
+                start_after,
+                limit,
+            } => Ok(to_json_binary(&self.swaps(
+                deps,
+                env,
+                include_expired.unwrap_or(false),
+                start_after,
+                limit,
+            )?)?),
+            QueryMsg::OwnerHistory {
+                token_id,
+                limit,
+                include_invalid,
+            } => Ok(to_json_binary(&self.owner_history(
+                deps,
+                env,
+                token_id,
+                limit,
+                include_invalid.unwrap_or(false),
+            )?)?),
+            QueryMsg::OwnerHistoryLength {} => {
+                Ok(to_json_binary(&self.owner_history_length(deps)?)?)
+            }
+            QueryMsg::MintInfo {
+                token_id,
+                include_invalid,
+            } => Ok(to_json_binary(&self.mint_info(
+                deps,
+                env,
+                token_id,
+                include_invalid.unwrap_or(false),
+            )?)?),
+            QueryMsg::ApprovalsSummary {
+                token_id,
+                include_invalid,
+            } => Ok(to_json_binary(&self.approvals_summary(
+                deps,
+                env,
+                token_id,
+                include_invalid.unwrap_or(false),
+            )?)?),
             QueryMsg::Extension { msg: _ } => Ok(Binary::default()),
         }
     }
@@ -129,6 +186,16 @@ impl<'a> Cw721ExpirationContract<'a> {
     pub fn ownership(deps: Deps) -> StdResult<cw_ownable::Ownership<Addr>> {
         cw_ownable::get_ownership(deps.storage)
     }
+
+    pub fn owner_history_length(&self, deps: Deps) -> StdResult<OwnerHistoryLengthResponse> {
+        Ok(OwnerHistoryLengthResponse {
+            length: self.base_contract.owner_history_length(deps.storage)?,
+        })
+    }
+
+    pub fn transfer_policy(&self, deps: Deps) -> StdResult<TransferPolicyResponse> {
+        Ok(self.base_contract.transfer_policy(deps.storage)?.into())
+    }
 }
 
 // queries
@@ -278,6 +345,76 @@ impl<'a> Cw721ExpirationContract<'a> {
         Ok(TokensResponse { tokens: filtered })
     }
 
+    pub fn mint_info(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+        include_invalid: bool,
+    ) -> Result<MintInfoResponse, ContractError> {
+        if !include_invalid {
+            self.assert_valid_nft(deps, &env, token_id.as_str())?;
+        }
+        Ok(self.base_contract.mint_info(deps, token_id)?)
+    }
+
+    pub fn approvals_summary(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+        include_invalid: bool,
+    ) -> Result<ApprovalsSummaryResponse, ContractError> {
+        if !include_invalid {
+            self.assert_valid_nft(deps, &env, token_id.as_str())?;
+        }
+        Ok(self.base_contract.approvals_summary(deps, env, token_id)?)
+    }
+
+    /// expired tokens cannot be transferred at all, so they are reported as an error
+    pub fn can_transfer(
+        &self,
+        deps: Deps,
+        env: Env,
+        spender: String,
+        token_id: String,
+    ) -> Result<CanTransferResponse, ContractError> {
+        self.assert_valid_nft(deps, &env, token_id.as_str())?;
+        Ok(self
+            .base_contract
+            .can_transfer(deps, env, spender, token_id)?)
+    }
+
+    pub fn swap(&self, deps: Deps, offered_token_id: String) -> StdResult<SwapResponse> {
+        self.base_contract.swap(deps, offered_token_id)
+    }
+
+    pub fn swaps(
+        &self,
+        deps: Deps,
+        env: Env,
+        include_expired: bool,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<SwapsResponse> {
+        self.base_contract
+            .swaps(deps, env, include_expired, start_after, limit)
+    }
+
+    pub fn owner_history(
+        &self,
+        deps: Deps,
+        env: Env,
+        token_id: String,
+        limit: Option<u32>,
+        include_invalid: bool,
+    ) -> Result<OwnerHistoryResponse, ContractError> {
+        if !include_invalid {
+            self.assert_valid_nft(deps, &env, token_id.as_str())?;
+        }
+        Ok(self.base_contract.owner_history(deps, token_id, limit)?)
+    }
+
     pub fn all_nft_info(
         &self,
         deps: Deps,
@@ -5,6 +5,8 @@ use crate::Extension;
 
 pub struct Cw721ExpirationContract<'a> {
     pub expiration_days: Item<'a, u16>, // max 65535 days
+    /// Mint times of tokens minted before the base contract tracked `mint_info`. Only read as
+    /// a fallback so those tokens keep expiring after a migration.
     pub mint_timestamps: Map<'a, &'a str, Timestamp>,
     pub base_contract: cw721_base::Cw721Contract<'a, Extension, Empty, Empty, Empty>,
 }
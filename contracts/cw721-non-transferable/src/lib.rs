@@ -89,7 +89,7 @@ pub mod entry {
                     token_uri,
                     extension,
                 } => Cw721NonTransferableContract::default()
-                    .mint(deps, info, token_id, owner, token_uri, extension),
+                    .mint_with_info(deps, env, info, token_id, owner, token_uri, extension),
                 _ => Err(ContractError::Ownership(
                     cw721_base::OwnershipError::NotOwner,
                 )),
@@ -1,4 +1,5 @@
 mod msg;
+mod querier;
 mod query;
 mod receiver;
 mod traits;
@@ -6,6 +7,7 @@ mod traits;
 pub use cw_utils::Expiration;
 
 pub use crate::msg::Cw721ExecuteMsg;
+pub use crate::querier::Cw721Querier;
 pub use crate::query::{
     AllNftInfoResponse, Approval, ApprovalResponse, ApprovalsResponse, ContractInfoResponse,
     Cw721QueryMsg, NftInfoResponse, NumTokensResponse, OperatorResponse, OperatorsResponse,
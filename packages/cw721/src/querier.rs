@@ -0,0 +1,62 @@
+use cosmwasm_std::{CustomQuery, Empty, QuerierWrapper, StdResult};
+use serde::de::DeserializeOwned;
+
+use crate::{ApprovalResponse, Cw721QueryMsg, NftInfoResponse, NumTokensResponse, OwnerOfResponse};
+
+/// Cw721Querier wraps a QuerierWrapper to make typed queries against a cw721 contract
+pub struct Cw721Querier<'a, C: CustomQuery = Empty> {
+    querier: QuerierWrapper<'a, C>,
+    contract: String,
+}
+
+impl<'a, C: CustomQuery> Cw721Querier<'a, C> {
+    pub fn new(querier: QuerierWrapper<'a, C>, contract: impl Into<String>) -> Self {
+        Self {
+            querier,
+            contract: contract.into(),
+        }
+    }
+
+    pub fn owner_of(
+        &self,
+        token_id: impl Into<String>,
+        include_expired: bool,
+    ) -> StdResult<OwnerOfResponse> {
+        self.query(&Cw721QueryMsg::OwnerOf {
+            token_id: token_id.into(),
+            include_expired: Some(include_expired),
+        })
+    }
+
+    pub fn approval(
+        &self,
+        token_id: impl Into<String>,
+        spender: impl Into<String>,
+        include_expired: bool,
+    ) -> StdResult<ApprovalResponse> {
+        self.query(&Cw721QueryMsg::Approval {
+            token_id: token_id.into(),
+            spender: spender.into(),
+            include_expired: Some(include_expired),
+        })
+    }
+
+    pub fn num_tokens(&self) -> StdResult<u64> {
+        let res: NumTokensResponse = self.query(&Cw721QueryMsg::NumTokens {})?;
+        Ok(res.count)
+    }
+
+    /// With metadata extension
+    pub fn nft_info<T: DeserializeOwned>(
+        &self,
+        token_id: impl Into<String>,
+    ) -> StdResult<NftInfoResponse<T>> {
+        self.query(&Cw721QueryMsg::NftInfo {
+            token_id: token_id.into(),
+        })
+    }
+
+    fn query<T: DeserializeOwned>(&self, msg: &Cw721QueryMsg) -> StdResult<T> {
+        self.querier.query_wasm_smart(&self.contract, msg)
+    }
+}